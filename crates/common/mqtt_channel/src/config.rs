@@ -1,4 +1,9 @@
-use crate::TopicFilter;
+use crate::{MqttError, TopicFilter};
+use std::num::NonZeroU16;
+use std::time::Duration;
+
+/// The shortest keep alive interval accepted by `rumqttc`
+pub(crate) const MIN_KEEP_ALIVE: Duration = Duration::from_secs(5);
 
 /// Configuration of an MQTT connection
 #[derive(Debug, Clone)]
@@ -23,6 +28,9 @@ pub struct Config {
 
     /// The list of topics to subscribe to on connect
     ///
+    /// These subscriptions are renewed on each reconnect,
+    /// unless the broker has kept them in the session of this client.
+    ///
     /// Default: An empty topic list
     pub subscriptions: TopicFilter,
 
@@ -41,6 +49,25 @@ pub struct Config {
     ///
     /// Default: `1024 * 1024`.
     pub max_packet_size: usize,
+
+    /// Interval at which the client pings the broker when the connection is idle
+    ///
+    /// Must be at least 5 seconds: a shorter interval is rejected on connect.
+    ///
+    /// Default: 60 seconds.
+    pub keep_alive: Duration,
+
+    /// Time given to the broker to acknowledge a connection request
+    ///
+    /// Only whole seconds are used; a timeout shorter than 1 second is rejected on connect.
+    ///
+    /// Default: 5 seconds.
+    pub connection_timeout: Duration,
+
+    /// Maximum number of outgoing QoS 1 and QoS 2 messages not yet acknowledged by the broker
+    ///
+    /// Default: `100`.
+    pub max_inflight: NonZeroU16,
 }
 
 /// By default a client connects the local MQTT broker.
@@ -54,6 +81,9 @@ impl Default for Config {
             clean_session: false,
             queue_capacity: 1024,
             max_packet_size: 1024 * 1024,
+            keep_alive: Duration::from_secs(60),
+            connection_timeout: Duration::from_secs(5),
+            max_inflight: NonZeroU16::new(100).expect("100 is not zero"),
         }
     }
 }
@@ -120,8 +150,38 @@ impl Config {
         }
     }
 
+    /// Set the keep alive interval
+    pub fn with_keep_alive(self, keep_alive: Duration) -> Self {
+        Self { keep_alive, ..self }
+    }
+
+    /// Set the connection timeout
+    pub fn with_connection_timeout(self, connection_timeout: Duration) -> Self {
+        Self {
+            connection_timeout,
+            ..self
+        }
+    }
+
+    /// Set the maximum number of unacknowledged outgoing messages
+    pub fn with_max_inflight(self, max_inflight: NonZeroU16) -> Self {
+        Self {
+            max_inflight,
+            ..self
+        }
+    }
+
     /// Wrap this config into an internal set of options for `rumqttc`.
-    pub(crate) fn mqtt_options(&self) -> rumqttc::MqttOptions {
+    ///
+    /// Fails if the keep alive interval or the connection timeout are out of the range supported by `rumqttc`.
+    pub(crate) fn mqtt_options(&self) -> Result<rumqttc::MqttOptions, MqttError> {
+        if self.keep_alive < MIN_KEEP_ALIVE {
+            return Err(MqttError::InvalidKeepAlive(self.keep_alive));
+        }
+        if self.connection_timeout.as_secs() == 0 {
+            return Err(MqttError::InvalidConnectionTimeout(self.connection_timeout));
+        }
+
         let id = match &self.session_name {
             None => std::iter::repeat_with(fastrand::lowercase)
                 .take(10)
@@ -139,7 +199,10 @@ impl Config {
         }
 
         mqtt_options.set_max_packet_size(self.max_packet_size, self.max_packet_size);
+        mqtt_options.set_keep_alive(self.keep_alive);
+        mqtt_options.set_connection_timeout(self.connection_timeout.as_secs());
+        mqtt_options.set_inflight(self.max_inflight.get());
 
-        mqtt_options
+        Ok(mqtt_options)
     }
 }
@@ -109,7 +109,7 @@ impl Connection {
         mut message_sender: mpsc::UnboundedSender<Message>,
         mut error_sender: mpsc::UnboundedSender<MqttError>,
     ) -> Result<(AsyncClient, EventLoop), MqttError> {
-        let mqtt_options = config.mqtt_options();
+        let mqtt_options = config.mqtt_options()?;
         let (mqtt_client, mut event_loop) = AsyncClient::new(mqtt_options, config.queue_capacity);
 
         loop {
@@ -178,14 +178,27 @@ impl Connection {
                         eprintln!("ERROR: Connection Error {}", err);
                     }
                     // Workaround for  https://github.com/bytebeamio/rumqtt/issues/250
-                    // If session_name is not provided, then re-subscribe
-                    else if config.session_name.is_none() {
+                    // Re-subscribe if session_name is not provided
+                    // or if the broker has not kept the session state of this client.
+                    else if config.session_name.is_none() || !ack.session_present {
                         let subscriptions = config.subscriptions.filters();
-                        // Need check here otherwise it will hang waiting for a SubAck, and none will come when there is no subscription.
-                        if subscriptions.is_empty() {
-                            break;
+                        // Nothing to re-subscribe to, and no SubAck will come.
+                        if !subscriptions.is_empty() {
+                            if let Err(err) =
+                                Connection::subscribe_to_topics(&mqtt_client, subscriptions).await
+                            {
+                                // Errors on send are ignored: it just means the client has closed the receiving channel.
+                                let _ = error_sender.send(err).await;
+                            }
                         }
-                        Connection::subscribe_to_topics(&mqtt_client, subscriptions).await?;
+                    }
+                }
+
+                Ok(Event::Incoming(Packet::SubAck(ack))) => {
+                    // Forward the failures of a re-subscription, which would otherwise go unnoticed.
+                    if let Some(err) = MqttError::maybe_subscription_error(&ack) {
+                        // Errors on send are ignored: it just means the client has closed the receiving channel.
+                        let _ = error_sender.send(err).await;
                     }
                 }
 
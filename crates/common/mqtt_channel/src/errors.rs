@@ -10,6 +10,15 @@ pub enum MqttError {
     #[error("Invalid session: a session name must be provided")]
     InvalidSessionConfig,
 
+    #[error(
+        "Invalid keep alive interval: {0:?}, it must be at least {:?}",
+        crate::config::MIN_KEEP_ALIVE
+    )]
+    InvalidKeepAlive(std::time::Duration),
+
+    #[error("Invalid connection timeout: {0:?}, it must be at least 1 second")]
+    InvalidConnectionTimeout(std::time::Duration),
+
     #[error("MQTT client error: {0}")]
     ClientError(#[from] rumqttc::ClientError),
 
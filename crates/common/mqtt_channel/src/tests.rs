@@ -4,10 +4,19 @@ mod tests {
     use futures::{SinkExt, StreamExt};
     use serial_test::serial;
     use std::convert::TryInto;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
     use std::time::Duration;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+    use tokio::net::{TcpListener, TcpStream};
+    use tokio::task::JoinHandle;
 
     const TIMEOUT: Duration = Duration::from_millis(1000);
 
+    /// Long enough for a client to notice a disconnection, pause one second and reconnect
+    const RECONNECT_TIMEOUT: Duration = Duration::from_secs(3);
+
     #[tokio::test]
     #[serial]
     async fn subscribing_to_messages() -> Result<(), anyhow::Error> {
@@ -453,6 +462,296 @@ mod tests {
         ));
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn resubscribing_when_the_broker_has_lost_the_session() -> Result<(), anyhow::Error> {
+        // Given an MQTT broker, reached through a proxy that can cut the connections
+        let broker = mqtt_tests::test_mqtt_broker();
+        let mut proxy = BrokerProxy::start(broker.port).await?;
+        let mqtt_config = Config::default().with_port(proxy.port);
+
+        // A client that connects with a well-known session name, subscribing to some topic.
+        let session_name = "forget_me";
+        let topic = "my/forgotten/topic";
+        let mqtt_config = mqtt_config
+            .with_session_name(session_name)
+            .with_subscriptions(topic.try_into()?);
+        let mut con = Connection::new(&mqtt_config).await?;
+        assert!(matches!(
+            proxy.next_packet().await,
+            Some(BrokerPacket::ConnAck { .. })
+        ));
+        assert_eq!(Some(BrokerPacket::SubAck), proxy.next_packet().await);
+
+        // When the client is disconnected
+        proxy.refuse_connections();
+        proxy.disconnect_all();
+        assert!(matches!(
+            tokio::time::timeout(RECONNECT_TIMEOUT, con.errors.next()).await,
+            Ok(Some(_))
+        ));
+
+        // And the broker loses the session of this client while it is disconnected
+        clear_session(&mqtt_config.clone().with_port(broker.port)).await?;
+        proxy.accept_connections();
+
+        // Then, on reconnect, the broker tells the client that there is no session
+        assert_eq!(
+            Some(BrokerPacket::ConnAck {
+                session_present: false
+            }),
+            proxy.next_packet().await
+        );
+
+        // And the client re-subscribes
+        assert_eq!(Some(BrokerPacket::SubAck), proxy.next_packet().await);
+
+        // So messages published on the topic are still received.
+        broker
+            .publish(topic, "msg sent after the session loss")
+            .await?;
+        assert_eq!(
+            MaybeMessage::Next(message(topic, "msg sent after the session loss")),
+            next_message(&mut con.received).await
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn a_client_without_subscriptions_survives_a_reconnect() -> Result<(), anyhow::Error> {
+        // Given an MQTT broker, reached through a proxy that can cut the connections
+        let broker = mqtt_tests::test_mqtt_broker();
+        let mut proxy = BrokerProxy::start(broker.port).await?;
+        let mqtt_config = Config::default().with_port(proxy.port);
+
+        // A client with no subscriptions
+        let topic = "some/output/topic";
+        let mut con = Connection::new(&mqtt_config).await?;
+        let mut messages = broker.messages_published_on(topic).await;
+        assert!(matches!(
+            proxy.next_packet().await,
+            Some(BrokerPacket::ConnAck { .. })
+        ));
+
+        // When the client is disconnected
+        proxy.disconnect_all();
+        assert!(matches!(
+            tokio::time::timeout(RECONNECT_TIMEOUT, con.errors.next()).await,
+            Ok(Some(_))
+        ));
+
+        // Then the client reconnects
+        assert!(matches!(
+            proxy.next_packet().await,
+            Some(BrokerPacket::ConnAck { .. })
+        ));
+
+        // Its channel of received messages is not closed
+        assert_eq!(MaybeMessage::Timeout, next_message(&mut con.received).await);
+
+        // And its messages are still published.
+        con.published
+            .send(message(topic, "msg sent after a reconnect"))
+            .await?;
+        mqtt_tests::assert_received(
+            &mut messages,
+            RECONNECT_TIMEOUT,
+            vec!["msg sent after a reconnect"],
+        )
+        .await;
+
+        Ok(())
+    }
+
+    /// The packets sent by the broker, as seen by a `BrokerProxy`
+    #[derive(Debug, Clone, Eq, PartialEq)]
+    enum BrokerPacket {
+        ConnAck { session_present: bool },
+        SubAck,
+        Other { packet_type: u8 },
+    }
+
+    /// A TCP proxy in front of the test MQTT broker.
+    ///
+    /// The test broker can be neither stopped nor restarted.
+    /// This proxy is used instead to cut the connections of the clients,
+    /// and to observe the packets sent by the broker to the clients.
+    struct BrokerProxy {
+        port: u16,
+        accepting: Arc<AtomicBool>,
+        connections: Arc<Mutex<Vec<JoinHandle<()>>>>,
+        packets: futures::channel::mpsc::UnboundedReceiver<BrokerPacket>,
+    }
+
+    impl BrokerProxy {
+        async fn start(broker_port: u16) -> Result<BrokerProxy, anyhow::Error> {
+            let listener = TcpListener::bind("127.0.0.1:0").await?;
+            let port = listener.local_addr()?.port();
+            let accepting = Arc::new(AtomicBool::new(true));
+            let connections = Arc::new(Mutex::new(Vec::new()));
+            let (packet_sender, packets) = futures::channel::mpsc::unbounded();
+
+            let proxy_accepting = accepting.clone();
+            let proxy_connections = connections.clone();
+            tokio::spawn(async move {
+                while let Ok((client, _)) = listener.accept().await {
+                    if !proxy_accepting.load(Ordering::SeqCst) {
+                        // The client connection is closed on drop
+                        continue;
+                    }
+                    let packet_sender = packet_sender.clone();
+                    let connection = tokio::spawn(async move {
+                        if let Ok(broker) = TcpStream::connect(("127.0.0.1", broker_port)).await {
+                            let _ = BrokerProxy::forward(client, broker, packet_sender).await;
+                        }
+                    });
+                    proxy_connections.lock().unwrap().push(connection);
+                }
+            });
+
+            Ok(BrokerProxy {
+                port,
+                accepting,
+                connections,
+                packets,
+            })
+        }
+
+        /// Cut all the current connections, as a broker restart would do
+        fn disconnect_all(&self) {
+            for connection in self.connections.lock().unwrap().drain(..) {
+                connection.abort();
+            }
+        }
+
+        /// Close on accept any new connection, as a broker being down would do
+        fn refuse_connections(&self) {
+            self.accepting.store(false, Ordering::SeqCst);
+        }
+
+        fn accept_connections(&self) {
+            self.accepting.store(true, Ordering::SeqCst);
+        }
+
+        /// The next packet sent by the broker to any of the clients
+        async fn next_packet(&mut self) -> Option<BrokerPacket> {
+            tokio::time::timeout(RECONNECT_TIMEOUT, self.packets.next())
+                .await
+                .ok()
+                .flatten()
+        }
+
+        async fn forward(
+            client: TcpStream,
+            broker: TcpStream,
+            packet_sender: futures::channel::mpsc::UnboundedSender<BrokerPacket>,
+        ) -> std::io::Result<()> {
+            let (mut client_input, mut client_output) = client.into_split();
+            let (mut broker_input, mut broker_output) = broker.into_split();
+
+            let upstream = tokio::io::copy(&mut client_input, &mut broker_output);
+            let downstream =
+                BrokerProxy::forward_packets(&mut broker_input, &mut client_output, packet_sender);
+
+            futures::future::try_join(upstream, downstream).await?;
+            Ok(())
+        }
+
+        async fn forward_packets(
+            broker_input: &mut OwnedReadHalf,
+            client_output: &mut OwnedWriteHalf,
+            packet_sender: futures::channel::mpsc::UnboundedSender<BrokerPacket>,
+        ) -> std::io::Result<()> {
+            loop {
+                let packet = BrokerProxy::read_packet(broker_input).await?;
+                let _ = packet_sender.unbounded_send(BrokerProxy::decode(&packet));
+                client_output.write_all(&packet).await?;
+            }
+        }
+
+        async fn read_packet(input: &mut OwnedReadHalf) -> std::io::Result<Vec<u8>> {
+            let mut packet = vec![input.read_u8().await?];
+
+            // The remaining length is encoded on up to 4 bytes, 7 bits per byte
+            let mut remaining_length = 0;
+            for i in 0..4 {
+                let byte = input.read_u8().await?;
+                packet.push(byte);
+                remaining_length += ((byte & 0x7f) as usize) << (7 * i);
+                if byte & 0x80 == 0 {
+                    break;
+                }
+            }
+
+            let header_length = packet.len();
+            packet.resize(header_length + remaining_length, 0);
+            input.read_exact(&mut packet[header_length..]).await?;
+            Ok(packet)
+        }
+
+        fn decode(packet: &[u8]) -> BrokerPacket {
+            match packet[0] >> 4 {
+                // The session present flag is the lowest bit of the first byte after the fixed header
+                2 => BrokerPacket::ConnAck {
+                    session_present: packet[2] & 0x01 == 0x01,
+                },
+                9 => BrokerPacket::SubAck,
+                packet_type => BrokerPacket::Other { packet_type },
+            }
+        }
+    }
+
+    #[test]
+    fn default_connection_settings() {
+        let mqtt_config = Config::default();
+
+        assert_eq!(mqtt_config.keep_alive, Duration::from_secs(60));
+        assert_eq!(mqtt_config.connection_timeout, Duration::from_secs(5));
+        assert_eq!(mqtt_config.max_inflight.get(), 100);
+    }
+
+    #[test]
+    fn custom_connection_settings() -> Result<(), anyhow::Error> {
+        let max_inflight = std::num::NonZeroU16::new(10).expect("not zero");
+        let mqtt_config = Config::default()
+            .with_keep_alive(Duration::from_secs(30))
+            .with_connection_timeout(Duration::from_secs(20))
+            .with_max_inflight(max_inflight);
+
+        assert_eq!(mqtt_config.keep_alive, Duration::from_secs(30));
+        assert_eq!(mqtt_config.connection_timeout, Duration::from_secs(20));
+        assert_eq!(mqtt_config.max_inflight, max_inflight);
+
+        let mqtt_options = mqtt_config.mqtt_options()?;
+        assert_eq!(mqtt_options.keep_alive(), Duration::from_secs(30));
+        assert_eq!(mqtt_options.connection_timeout(), 20);
+        assert_eq!(mqtt_options.inflight(), 10);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn a_keep_alive_shorter_than_5_seconds_is_rejected() {
+        let mqtt_config = Config::default().with_keep_alive(Duration::from_secs(1));
+
+        assert!(matches!(
+            Connection::new(&mqtt_config).await,
+            Err(MqttError::InvalidKeepAlive(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn a_connection_timeout_shorter_than_1_second_is_rejected() {
+        let mqtt_config = Config::default().with_connection_timeout(Duration::from_millis(500));
+
+        assert!(matches!(
+            Connection::new(&mqtt_config).await,
+            Err(MqttError::InvalidConnectionTimeout(_))
+        ));
+    }
+
     #[tokio::test]
     #[serial]
     async fn ensure_that_all_messages_are_sent_before_disconnect() -> Result<(), anyhow::Error> {
@@ -67,6 +67,9 @@ pub enum ConfigSettingError {
     #[error("Derivation for `{key}` failed: {cause}")]
     DerivationFailed { key: &'static str, cause: String },
 
+    #[error("Invalid value for `{key}`: {cause}")]
+    InvalidValue { key: &'static str, cause: String },
+
     #[error("Config value {key}, cannot be configured: {message} ")]
     SettingIsNotConfigurable {
         key: &'static str,
@@ -0,0 +1,97 @@
+use std::convert::{TryFrom, TryInto};
+
+/// Interval, in seconds, at which an idle MQTT connection is checked with a ping.
+///
+/// Mosquitto rejects keep alive intervals shorter than 5 seconds.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct KeepAliveInterval(pub u16);
+
+const MIN_KEEP_ALIVE_INTERVAL: u16 = 5;
+
+#[derive(thiserror::Error, Debug)]
+#[error(
+    "Invalid keep alive interval: '{input}'. Expected a number of seconds between {} and {}.",
+    MIN_KEEP_ALIVE_INTERVAL,
+    u16::MAX
+)]
+pub struct InvalidKeepAliveInterval {
+    input: String,
+}
+
+impl TryFrom<String> for KeepAliveInterval {
+    type Error = InvalidKeepAliveInterval;
+
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        match input.as_str().parse::<u16>() {
+            Ok(seconds) => KeepAliveInterval::try_from(seconds),
+            Err(_) => Err(InvalidKeepAliveInterval { input }),
+        }
+    }
+}
+
+impl TryFrom<u16> for KeepAliveInterval {
+    type Error = InvalidKeepAliveInterval;
+
+    fn try_from(seconds: u16) -> Result<Self, Self::Error> {
+        if seconds >= MIN_KEEP_ALIVE_INTERVAL {
+            Ok(KeepAliveInterval(seconds))
+        } else {
+            Err(InvalidKeepAliveInterval {
+                input: seconds.to_string(),
+            })
+        }
+    }
+}
+
+impl TryInto<String> for KeepAliveInterval {
+    type Error = std::convert::Infallible;
+
+    fn try_into(self) -> Result<String, Self::Error> {
+        Ok(format!("{}", self.0))
+    }
+}
+
+impl From<KeepAliveInterval> for u16 {
+    fn from(val: KeepAliveInterval) -> Self {
+        val.0
+    }
+}
+
+#[cfg(test)]
+use assert_matches::*;
+#[test]
+fn conversion_from_valid_interval_succeeds() {
+    assert_matches!(
+        KeepAliveInterval::try_from("30".to_string()),
+        Ok(KeepAliveInterval(30))
+    );
+}
+
+#[test]
+fn conversion_from_too_short_interval_fails() {
+    assert_matches!(
+        KeepAliveInterval::try_from("4".to_string()),
+        Err(InvalidKeepAliveInterval { .. })
+    );
+}
+
+#[test]
+fn conversion_from_too_short_integer_fails() {
+    assert_matches!(
+        KeepAliveInterval::try_from(2u16),
+        Err(InvalidKeepAliveInterval { .. })
+    );
+}
+
+#[test]
+fn conversion_from_longer_integer_fails() {
+    assert_matches!(
+        KeepAliveInterval::try_from("66000".to_string()),
+        Err(InvalidKeepAliveInterval { .. })
+    );
+}
+
+#[test]
+fn conversion_from_interval_to_string() {
+    assert_matches!(TryInto::<String>::try_into(KeepAliveInterval(30)), Ok(interval_str) if interval_str == "30");
+}
@@ -2,7 +2,12 @@ pub mod connect_url;
 pub mod file_path;
 pub mod flag;
 pub mod ipaddress;
+pub mod keep_alive_interval;
 pub mod port;
 pub mod templates_set;
+pub mod value_coercion;
 
-pub use self::{connect_url::*, file_path::*, flag::*, ipaddress::*, port::*, templates_set::*};
+pub use self::{
+    connect_url::*, file_path::*, flag::*, ipaddress::*, keep_alive_interval::*, port::*,
+    templates_set::*, value_coercion::*,
+};
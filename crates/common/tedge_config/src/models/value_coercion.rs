@@ -0,0 +1,70 @@
+use std::convert::{TryFrom, TryInto};
+
+/// How a mapper reads measurement values that are not plain JSON numbers.
+///
+/// - `strict`: such values are rejected.
+/// - `lenient`: booleans, numeric strings and integers out of the 32-bit range are read as floats.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ValueCoercion {
+    Strict,
+    Lenient,
+}
+
+impl Default for ValueCoercion {
+    fn default() -> Self {
+        ValueCoercion::Strict
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+#[error("Invalid value coercion: '{input}'. Supported values are: strict, lenient")]
+pub struct InvalidValueCoercion {
+    input: String,
+}
+
+impl TryFrom<String> for ValueCoercion {
+    type Error = InvalidValueCoercion;
+
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        match input.as_str() {
+            "strict" => Ok(ValueCoercion::Strict),
+            "lenient" => Ok(ValueCoercion::Lenient),
+            _ => Err(InvalidValueCoercion { input }),
+        }
+    }
+}
+
+impl TryInto<String> for ValueCoercion {
+    type Error = std::convert::Infallible;
+
+    fn try_into(self) -> Result<String, Self::Error> {
+        match self {
+            ValueCoercion::Strict => Ok("strict".to_string()),
+            ValueCoercion::Lenient => Ok("lenient".to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+use assert_matches::*;
+#[test]
+fn conversion_from_valid_coercion_succeeds() {
+    assert_matches!(
+        ValueCoercion::try_from("lenient".to_string()),
+        Ok(ValueCoercion::Lenient)
+    );
+}
+
+#[test]
+fn conversion_from_unknown_coercion_fails() {
+    assert_matches!(
+        ValueCoercion::try_from("loose".to_string()),
+        Err(InvalidValueCoercion { .. })
+    );
+}
+
+#[test]
+fn conversion_from_coercion_to_string() {
+    assert_matches!(TryInto::<String>::try_into(ValueCoercion::Strict), Ok(coercion_str) if coercion_str == "strict");
+}
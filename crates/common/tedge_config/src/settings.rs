@@ -177,6 +177,92 @@ impl ConfigSetting for AzureMapperTimestamp {
     type Value = Flag;
 }
 
+///
+/// Keep alive interval, in seconds, of the MQTT bridge connection to Cumulocity.
+///
+/// Example: 30
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct C8yBridgeKeepAliveIntervalSetting;
+
+impl ConfigSetting for C8yBridgeKeepAliveIntervalSetting {
+    const KEY: &'static str = "c8y.bridge.keepalive_interval";
+
+    const DESCRIPTION: &'static str = concat!(
+        "Number of seconds after which the Cumulocity bridge pings the cloud if no other traffic occurred, ",
+        "used to detect dropped connections (e.g. cellular links behind a NAT). ",
+        "Must be at least 5. When not set, the mosquitto default of 60 is used. ",
+        "Example: 30"
+    );
+
+    type Value = KeepAliveInterval;
+}
+
+///
+/// Keep alive interval, in seconds, of the MQTT bridge connection to Azure IoT.
+///
+/// Example: 30
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct AzureBridgeKeepAliveIntervalSetting;
+
+impl ConfigSetting for AzureBridgeKeepAliveIntervalSetting {
+    const KEY: &'static str = "az.bridge.keepalive_interval";
+
+    const DESCRIPTION: &'static str = concat!(
+        "Number of seconds after which the Azure IoT bridge pings the cloud if no other traffic occurred, ",
+        "used to detect dropped connections (e.g. cellular links behind a NAT). ",
+        "Must be at least 5. When not set, the mosquitto default of 60 is used. ",
+        "Example: 30"
+    );
+
+    type Value = KeepAliveInterval;
+}
+
+///
+/// How the Cumulocity mapper reads measurement values that are not plain JSON numbers.
+///
+/// Example: lenient
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct C8yMapperCoercionSetting;
+
+impl ConfigSetting for C8yMapperCoercionSetting {
+    const KEY: &'static str = "c8y.mapper.coercion";
+
+    const DESCRIPTION: &'static str = concat!(
+        "How the Cumulocity mapper reads measurement values that are not plain JSON numbers. ",
+        "With `strict`, such values are rejected. ",
+        "With `lenient`, booleans, numeric strings and large integers are converted to floats. ",
+        "When not set, `strict` is used. ",
+        "Example: lenient"
+    );
+
+    type Value = ValueCoercion;
+}
+
+///
+/// How the Azure mapper reads measurement values that are not plain JSON numbers.
+///
+/// Example: lenient
+///
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct AzureMapperCoercionSetting;
+
+impl ConfigSetting for AzureMapperCoercionSetting {
+    const KEY: &'static str = "az.mapper.coercion";
+
+    const DESCRIPTION: &'static str = concat!(
+        "How the Azure mapper reads measurement values that are not plain JSON numbers. ",
+        "With `strict`, such values are rejected. ",
+        "With `lenient`, booleans, numeric strings and large integers are converted to floats. ",
+        "When not set, `strict` is used. ",
+        "Example: lenient"
+    );
+
+    type Value = ValueCoercion;
+}
+
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub struct MqttPortSetting;
 
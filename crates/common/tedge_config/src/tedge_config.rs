@@ -330,6 +330,124 @@ impl ConfigSettingAccessor<MqttBindAddressSetting> for TEdgeConfig {
     }
 }
 
+impl ConfigSettingAccessor<C8yBridgeKeepAliveIntervalSetting> for TEdgeConfig {
+    fn query(
+        &self,
+        _setting: C8yBridgeKeepAliveIntervalSetting,
+    ) -> ConfigSettingResult<KeepAliveInterval> {
+        let seconds =
+            self.data
+                .c8y
+                .bridge_keepalive_interval
+                .ok_or(ConfigSettingError::ConfigNotSet {
+                    key: C8yBridgeKeepAliveIntervalSetting::KEY,
+                })?;
+        // The value may have been edited by hand in the config file.
+        KeepAliveInterval::try_from(seconds).map_err(|err| ConfigSettingError::InvalidValue {
+            key: C8yBridgeKeepAliveIntervalSetting::KEY,
+            cause: err.to_string(),
+        })
+    }
+
+    fn update(
+        &mut self,
+        _setting: C8yBridgeKeepAliveIntervalSetting,
+        value: KeepAliveInterval,
+    ) -> ConfigSettingResult<()> {
+        self.data.c8y.bridge_keepalive_interval = Some(value.into());
+        Ok(())
+    }
+
+    fn unset(&mut self, _setting: C8yBridgeKeepAliveIntervalSetting) -> ConfigSettingResult<()> {
+        self.data.c8y.bridge_keepalive_interval = None;
+        Ok(())
+    }
+}
+
+impl ConfigSettingAccessor<AzureBridgeKeepAliveIntervalSetting> for TEdgeConfig {
+    fn query(
+        &self,
+        _setting: AzureBridgeKeepAliveIntervalSetting,
+    ) -> ConfigSettingResult<KeepAliveInterval> {
+        let seconds =
+            self.data
+                .az
+                .bridge_keepalive_interval
+                .ok_or(ConfigSettingError::ConfigNotSet {
+                    key: AzureBridgeKeepAliveIntervalSetting::KEY,
+                })?;
+        // The value may have been edited by hand in the config file.
+        KeepAliveInterval::try_from(seconds).map_err(|err| ConfigSettingError::InvalidValue {
+            key: AzureBridgeKeepAliveIntervalSetting::KEY,
+            cause: err.to_string(),
+        })
+    }
+
+    fn update(
+        &mut self,
+        _setting: AzureBridgeKeepAliveIntervalSetting,
+        value: KeepAliveInterval,
+    ) -> ConfigSettingResult<()> {
+        self.data.az.bridge_keepalive_interval = Some(value.into());
+        Ok(())
+    }
+
+    fn unset(&mut self, _setting: AzureBridgeKeepAliveIntervalSetting) -> ConfigSettingResult<()> {
+        self.data.az.bridge_keepalive_interval = None;
+        Ok(())
+    }
+}
+
+impl ConfigSettingAccessor<C8yMapperCoercionSetting> for TEdgeConfig {
+    fn query(&self, _setting: C8yMapperCoercionSetting) -> ConfigSettingResult<ValueCoercion> {
+        self.data
+            .c8y
+            .mapper_coercion
+            .ok_or(ConfigSettingError::ConfigNotSet {
+                key: C8yMapperCoercionSetting::KEY,
+            })
+    }
+
+    fn update(
+        &mut self,
+        _setting: C8yMapperCoercionSetting,
+        value: ValueCoercion,
+    ) -> ConfigSettingResult<()> {
+        self.data.c8y.mapper_coercion = Some(value);
+        Ok(())
+    }
+
+    fn unset(&mut self, _setting: C8yMapperCoercionSetting) -> ConfigSettingResult<()> {
+        self.data.c8y.mapper_coercion = None;
+        Ok(())
+    }
+}
+
+impl ConfigSettingAccessor<AzureMapperCoercionSetting> for TEdgeConfig {
+    fn query(&self, _setting: AzureMapperCoercionSetting) -> ConfigSettingResult<ValueCoercion> {
+        self.data
+            .az
+            .mapper_coercion
+            .ok_or(ConfigSettingError::ConfigNotSet {
+                key: AzureMapperCoercionSetting::KEY,
+            })
+    }
+
+    fn update(
+        &mut self,
+        _setting: AzureMapperCoercionSetting,
+        value: ValueCoercion,
+    ) -> ConfigSettingResult<()> {
+        self.data.az.mapper_coercion = Some(value);
+        Ok(())
+    }
+
+    fn unset(&mut self, _setting: AzureMapperCoercionSetting) -> ConfigSettingResult<()> {
+        self.data.az.mapper_coercion = None;
+        Ok(())
+    }
+}
+
 impl ConfigSettingAccessor<MqttExternalPortSetting> for TEdgeConfig {
     fn query(&self, _setting: MqttExternalPortSetting) -> ConfigSettingResult<Port> {
         self.data
@@ -75,6 +75,12 @@ pub(crate) struct CumulocityConfigDto {
 
     /// Set of c8y templates used for subscriptions.
     pub(crate) smartrest_templates: Option<TemplatesSet>,
+
+    /// Keep alive interval, in seconds, of the bridge connection to Cumulocity.
+    pub(crate) bridge_keepalive_interval: Option<u16>,
+
+    /// How the Cumulocity mapper reads values that are not plain JSON numbers.
+    pub(crate) mapper_coercion: Option<ValueCoercion>,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize)]
@@ -84,6 +90,8 @@ pub(crate) struct AzureConfigDto {
     pub(crate) url: Option<ConnectUrl>,
     pub(crate) root_cert_path: Option<FilePath>,
     pub(crate) mapper_timestamp: Option<bool>,
+    pub(crate) bridge_keepalive_interval: Option<u16>,
+    pub(crate) mapper_coercion: Option<ValueCoercion>,
 }
 
 #[derive(Debug, Default, Deserialize, Serialize)]
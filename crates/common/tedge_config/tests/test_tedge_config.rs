@@ -552,6 +552,125 @@ fn set_az_keys_from_old_version_config() -> Result<(), TEdgeConfigError> {
     Ok(())
 }
 
+#[test]
+fn set_and_unset_bridge_keepalive_intervals() -> Result<(), TEdgeConfigError> {
+    let toml_conf = r#"
+[c8y]
+bridge_keepalive_interval = 30
+"#;
+
+    let (_tempdir, config_location) = create_temp_tedge_config(toml_conf)?;
+    let config_repo =
+        TEdgeConfigRepository::new_with_defaults(config_location, dummy_tedge_config_defaults());
+
+    {
+        let mut config = config_repo.load()?;
+
+        assert_eq!(
+            config.query(C8yBridgeKeepAliveIntervalSetting)?,
+            KeepAliveInterval(30)
+        );
+        assert!(config
+            .query_optional(AzureBridgeKeepAliveIntervalSetting)?
+            .is_none());
+
+        config.unset(C8yBridgeKeepAliveIntervalSetting)?;
+        config.update_string(AzureBridgeKeepAliveIntervalSetting, "20".into())?;
+        assert!(config
+            .update_string(C8yBridgeKeepAliveIntervalSetting, "1".into())
+            .is_err());
+        config_repo.store(&config)?;
+    }
+
+    {
+        let config = config_repo.load()?;
+
+        assert!(config
+            .query_optional(C8yBridgeKeepAliveIntervalSetting)?
+            .is_none());
+        assert_eq!(
+            config.query(AzureBridgeKeepAliveIntervalSetting)?,
+            KeepAliveInterval(20)
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn bridge_keepalive_intervals_edited_by_hand_are_validated() -> Result<(), TEdgeConfigError> {
+    let toml_conf = r#"
+[c8y]
+bridge_keepalive_interval = 2
+
+[az]
+bridge_keepalive_interval = 0
+"#;
+
+    let (_tempdir, config_location) = create_temp_tedge_config(toml_conf)?;
+    let config_repo =
+        TEdgeConfigRepository::new_with_defaults(config_location, dummy_tedge_config_defaults());
+    let config = config_repo.load()?;
+
+    assert_matches!(
+        config.query(C8yBridgeKeepAliveIntervalSetting),
+        Err(ConfigSettingError::InvalidValue {
+            key: "c8y.bridge.keepalive_interval",
+            ..
+        })
+    );
+    assert_matches!(
+        config.query_optional(AzureBridgeKeepAliveIntervalSetting),
+        Err(ConfigSettingError::InvalidValue {
+            key: "az.bridge.keepalive_interval",
+            ..
+        })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn set_and_unset_mapper_coercions() -> Result<(), TEdgeConfigError> {
+    let toml_conf = r#"
+[c8y]
+mapper_coercion = "lenient"
+"#;
+
+    let (_tempdir, config_location) = create_temp_tedge_config(toml_conf)?;
+    let config_repo =
+        TEdgeConfigRepository::new_with_defaults(config_location, dummy_tedge_config_defaults());
+
+    {
+        let mut config = config_repo.load()?;
+
+        assert_eq!(
+            config.query(C8yMapperCoercionSetting)?,
+            ValueCoercion::Lenient
+        );
+        assert!(config.query_optional(AzureMapperCoercionSetting)?.is_none());
+
+        config.unset(C8yMapperCoercionSetting)?;
+        config.update_string(AzureMapperCoercionSetting, "lenient".into())?;
+        assert!(config
+            .update_string(C8yMapperCoercionSetting, "loose".into())
+            .is_err());
+        config_repo.store(&config)?;
+    }
+
+    {
+        let config = config_repo.load()?;
+
+        assert!(config.query_optional(C8yMapperCoercionSetting)?.is_none());
+        assert_eq!(
+            config.query(AzureMapperCoercionSetting)?,
+            ValueCoercion::Lenient
+        );
+    }
+
+    Ok(())
+}
+
 #[test]
 fn test_parse_config_empty_file() -> Result<(), TEdgeConfigError> {
     let toml_conf = "";
@@ -30,17 +30,19 @@ pub enum CumulocityJsonError {
 /// Converts from thin-edge measurement JSON to C8Y measurement JSON
 pub fn from_thin_edge_json(input: &str) -> Result<String, CumulocityJsonError> {
     let timestamp = WallClock.now();
-    let c8y_vec = from_thin_edge_json_with_timestamp(input, timestamp, None)?;
+    let c8y_vec = from_thin_edge_json_with_timestamp(input, timestamp, None, Coercion::Strict)?;
     Ok(c8y_vec)
 }
 
-/// Converts from thin-edge Json to c8y_json with child id information
-pub fn from_thin_edge_json_with_child(
+/// Converts from thin-edge Json to c8y_json, with an optional child id,
+/// reading the measurement values that are not plain JSON numbers as told by `coercion`
+pub fn from_thin_edge_json_with_coercion(
     input: &str,
-    child_id: &str,
+    maybe_child_id: Option<&str>,
+    coercion: Coercion,
 ) -> Result<String, CumulocityJsonError> {
     let timestamp = WallClock.now();
-    let c8y_vec = from_thin_edge_json_with_timestamp(input, timestamp, Some(child_id))?;
+    let c8y_vec = from_thin_edge_json_with_timestamp(input, timestamp, maybe_child_id, coercion)?;
     Ok(c8y_vec)
 }
 
@@ -48,9 +50,10 @@ fn from_thin_edge_json_with_timestamp(
     input: &str,
     timestamp: OffsetDateTime,
     maybe_child_id: Option<&str>,
+    coercion: Coercion,
 ) -> Result<String, CumulocityJsonError> {
     let mut serializer = serializer::C8yJsonSerializer::new(timestamp, maybe_child_id);
-    parse_str(input, &mut serializer)?;
+    parse_str_with_coercion(input, &mut serializer, coercion)?;
     Ok(serializer.into_string()?)
 }
 
@@ -72,8 +75,12 @@ mod tests {
 
         let timestamp = datetime!(2021-04-08 0:00:0 +05:00);
 
-        let output =
-            from_thin_edge_json_with_timestamp(single_value_thin_edge_json, timestamp, None);
+        let output = from_thin_edge_json_with_timestamp(
+            single_value_thin_edge_json,
+            timestamp,
+            None,
+            Coercion::Strict,
+        );
 
         let expected_output = json!({
             "type": "ThinEdgeMeasurement",
@@ -99,6 +106,50 @@ mod tests {
         );
     }
 
+    #[test]
+    fn check_lenient_value_translation() {
+        let thin_edge_json = r#"{
+                  "temperature": "23.5",
+                  "door_open": true
+               }"#;
+
+        let timestamp = datetime!(2021-04-08 0:00:0 +05:00);
+
+        assert!(from_thin_edge_json_with_timestamp(
+            thin_edge_json,
+            timestamp,
+            None,
+            Coercion::Strict
+        )
+        .is_err());
+
+        let output =
+            from_thin_edge_json_with_timestamp(thin_edge_json, timestamp, None, Coercion::Lenient);
+
+        let expected_output = json!({
+            "type": "ThinEdgeMeasurement",
+            "time": timestamp
+                .format(&format_description::well_known::Rfc3339)
+                .unwrap()
+                .as_str(),
+            "temperature": {
+                "temperature": {
+                    "value": 23.5
+                }
+            },
+            "door_open": {
+                "door_open": {
+                    "value": 1.0
+                }
+            }
+        });
+
+        assert_json_eq!(
+            serde_json::from_str::<serde_json::Value>(output.unwrap().as_str()).unwrap(),
+            expected_output
+        );
+    }
+
     #[test]
     fn check_thin_edge_translation_with_timestamp() {
         let single_value_thin_edge_json = r#"{
@@ -144,8 +195,12 @@ mod tests {
 
         let timestamp = datetime!(2021-04-08 0:00:0 +05:00);
 
-        let output =
-            from_thin_edge_json_with_timestamp(multi_value_thin_edge_json, timestamp, None);
+        let output = from_thin_edge_json_with_timestamp(
+            multi_value_thin_edge_json,
+            timestamp,
+            None,
+            Coercion::Strict,
+        );
 
         let expected_output = json!({
             "type": "ThinEdgeMeasurement",
@@ -278,7 +333,12 @@ mod tests {
         expected_output: Value,
     ) {
         let timestamp = datetime!(2021-04-08 0:00:0 +05:00);
-        let output = from_thin_edge_json_with_timestamp(thin_edge_json, timestamp, Some(child_id));
+        let output = from_thin_edge_json_with_timestamp(
+            thin_edge_json,
+            timestamp,
+            Some(child_id),
+            Coercion::Strict,
+        );
         assert_json_eq!(
             serde_json::from_str::<serde_json::Value>(output.unwrap().as_str()).unwrap(),
             expected_output
@@ -46,9 +46,13 @@ impl ConfigKey {
             config_key!(C8yUrlSetting),
             config_key!(C8yRootCertPathSetting),
             config_key!(C8ySmartRestTemplates),
+            config_key!(C8yBridgeKeepAliveIntervalSetting),
+            config_key!(C8yMapperCoercionSetting),
             config_key!(AzureUrlSetting),
             config_key!(AzureRootCertPathSetting),
             config_key!(AzureMapperTimestamp),
+            config_key!(AzureBridgeKeepAliveIntervalSetting),
+            config_key!(AzureMapperCoercionSetting),
             config_key!(MqttBindAddressSetting),
             config_key!(MqttPortSetting),
             config_key!(MqttExternalPortSetting),
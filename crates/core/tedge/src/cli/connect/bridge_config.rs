@@ -20,6 +20,7 @@ pub struct BridgeConfig {
     pub try_private: bool,
     pub start_type: String,
     pub clean_session: bool,
+    pub keepalive_interval: Option<u16>,
     pub notifications: bool,
     pub notifications_local_only: bool,
     pub notification_topic: String,
@@ -52,6 +53,9 @@ impl BridgeConfig {
         writeln!(writer, "try_private {}", self.try_private)?;
         writeln!(writer, "start_type {}", self.start_type)?;
         writeln!(writer, "cleansession {}", self.clean_session)?;
+        if let Some(keepalive_interval) = self.keepalive_interval {
+            writeln!(writer, "keepalive_interval {}", keepalive_interval)?;
+        }
         writeln!(writer, "notifications {}", self.notifications)?;
         writeln!(
             writer,
@@ -121,6 +125,7 @@ mod test {
             try_private: false,
             start_type: "automatic".into(),
             clean_session: true,
+            keepalive_interval: None,
             notifications: false,
             notifications_local_only: false,
             notification_topic: "test_topic".into(),
@@ -183,6 +188,7 @@ bridge_attempt_unsubscribe false
             try_private: false,
             start_type: "automatic".into(),
             clean_session: true,
+            keepalive_interval: None,
             notifications: false,
             notifications_local_only: false,
             notification_topic: "test_topic".into(),
@@ -247,6 +253,7 @@ bridge_attempt_unsubscribe false
             try_private: false,
             start_type: "automatic".into(),
             clean_session: true,
+            keepalive_interval: Some(30),
             notifications: false,
             notifications_local_only: false,
             notification_topic: "test_topic".into(),
@@ -275,6 +282,7 @@ bridge_attempt_unsubscribe false
         expected.insert("start_type automatic");
         expected.insert("try_private false");
         expected.insert("cleansession true");
+        expected.insert("keepalive_interval 30");
         expected.insert("notifications false");
         expected.insert("notifications_local_only false");
         expected.insert("notification_topic test_topic");
@@ -380,6 +388,7 @@ bridge_attempt_unsubscribe false
             try_private: false,
             start_type: "automatic".into(),
             clean_session: true,
+            keepalive_interval: None,
             notifications: false,
             notifications_local_only: false,
             notification_topic: "test_topic".into(),
@@ -10,6 +10,7 @@ pub struct BridgeConfigAzureParams {
     pub bridge_root_cert_path: FilePath,
     pub bridge_certfile: FilePath,
     pub bridge_keyfile: FilePath,
+    pub keepalive_interval: Option<u16>,
 }
 
 impl From<BridgeConfigAzureParams> for BridgeConfig {
@@ -22,6 +23,7 @@ impl From<BridgeConfigAzureParams> for BridgeConfig {
             remote_clientid,
             bridge_certfile,
             bridge_keyfile,
+            keepalive_interval,
         } = params;
 
         let address = format!("{}:{}", connect_url.as_str(), mqtt_tls_port);
@@ -51,6 +53,7 @@ impl From<BridgeConfigAzureParams> for BridgeConfig {
             try_private: false,
             start_type: "automatic".into(),
             clean_session: false,
+            keepalive_interval,
             notifications: true,
             notifications_local_only: true,
             notification_topic: "tedge/health/mosquitto-az-bridge".into(),
@@ -77,6 +80,7 @@ fn test_bridge_config_from_azure_params() -> anyhow::Result<()> {
         bridge_root_cert_path: "./test_root.pem".into(),
         bridge_certfile: "./test-certificate.pem".into(),
         bridge_keyfile: "./test-private-key.pem".into(),
+        keepalive_interval: None,
     };
 
     let bridge = BridgeConfig::from(params);
@@ -103,6 +107,7 @@ fn test_bridge_config_from_azure_params() -> anyhow::Result<()> {
         try_private: false,
         start_type: "automatic".into(),
         clean_session: false,
+        keepalive_interval: None,
         notifications: true,
         notifications_local_only: true,
         notification_topic: "tedge/health/mosquitto-az-bridge".into(),
@@ -11,6 +11,7 @@ pub struct BridgeConfigC8yParams {
     pub bridge_certfile: FilePath,
     pub bridge_keyfile: FilePath,
     pub smartrest_templates: TemplatesSet,
+    pub keepalive_interval: Option<u16>,
 }
 
 impl From<BridgeConfigC8yParams> for BridgeConfig {
@@ -24,6 +25,7 @@ impl From<BridgeConfigC8yParams> for BridgeConfig {
             bridge_certfile,
             bridge_keyfile,
             smartrest_templates,
+            keepalive_interval,
         } = params;
         let address = format!("{}:{}", connect_url.as_str(), mqtt_tls_port);
 
@@ -90,6 +92,7 @@ impl From<BridgeConfigC8yParams> for BridgeConfig {
             try_private: false,
             start_type: "automatic".into(),
             clean_session: false,
+            keepalive_interval,
             notifications: true,
             notifications_local_only: true,
             notification_topic: "tedge/health/mosquitto-c8y-bridge".into(),
@@ -111,6 +114,7 @@ fn test_bridge_config_from_c8y_params() -> anyhow::Result<()> {
         bridge_certfile: "./test-certificate.pem".into(),
         bridge_keyfile: "./test-private-key.pem".into(),
         smartrest_templates: TemplatesSet::try_from(vec!["abc", "def"])?,
+        keepalive_interval: Some(30),
     };
 
     let bridge = BridgeConfig::from(params);
@@ -168,6 +172,7 @@ fn test_bridge_config_from_c8y_params() -> anyhow::Result<()> {
         try_private: false,
         start_type: "automatic".into(),
         clean_session: false,
+        keepalive_interval: Some(30),
         notifications: true,
         notifications_local_only: true,
         notification_topic: "tedge/health/mosquitto-c8y-bridge".into(),
@@ -187,6 +187,9 @@ impl ConnectCommand {
                     remote_clientid: config.query(DeviceIdSetting)?,
                     bridge_certfile: config.query(DeviceCertPathSetting)?,
                     bridge_keyfile: config.query(DeviceKeyPathSetting)?,
+                    keepalive_interval: config
+                        .query_optional(AzureBridgeKeepAliveIntervalSetting)?
+                        .map(Into::into),
                 };
 
                 Ok(BridgeConfig::from(params))
@@ -201,6 +204,9 @@ impl ConnectCommand {
                     bridge_certfile: config.query(DeviceCertPathSetting)?,
                     bridge_keyfile: config.query(DeviceKeyPathSetting)?,
                     smartrest_templates: config.query(C8ySmartRestTemplates)?,
+                    keepalive_interval: config
+                        .query_optional(C8yBridgeKeepAliveIntervalSetting)?
+                        .map(Into::into),
                 };
 
                 Ok(BridgeConfig::from(params))
@@ -3,12 +3,13 @@ use crate::core::{converter::*, error::*, size_threshold::SizeThreshold};
 use async_trait::async_trait;
 use clock::Clock;
 use mqtt_channel::{Message, TopicFilter};
-use thin_edge_json::serialize::ThinEdgeJsonSerializer;
+use thin_edge_json::{parser::Coercion, serialize::ThinEdgeJsonSerializer};
 
 pub struct AzureConverter {
     pub(crate) add_timestamp: bool,
     pub(crate) clock: Box<dyn Clock>,
     pub(crate) size_threshold: SizeThreshold,
+    pub(crate) coercion: Coercion,
     pub(crate) mapper_config: MapperConfig,
 }
 
@@ -23,10 +24,16 @@ impl AzureConverter {
             add_timestamp,
             clock,
             size_threshold,
+            coercion: Coercion::default(),
             mapper_config,
         }
     }
 
+    /// Read the measurement values that are not plain JSON numbers as told by `coercion`.
+    pub fn with_coercion(self, coercion: Coercion) -> Self {
+        Self { coercion, ..self }
+    }
+
     pub fn in_topic_filter() -> TopicFilter {
         make_valid_topic_filter_or_panic("tedge/measurements")
     }
@@ -44,7 +51,11 @@ impl Converter for AzureConverter {
         self.size_threshold.validate(input)?;
         let default_timestamp = self.add_timestamp.then(|| self.clock.now());
         let mut serializer = ThinEdgeJsonSerializer::new_with_timestamp(default_timestamp);
-        thin_edge_json::parser::parse_str(input.payload_str()?, &mut serializer)?;
+        thin_edge_json::parser::parse_str_with_coercion(
+            input.payload_str()?,
+            &mut serializer,
+            self.coercion,
+        )?;
 
         let payload = serializer.into_string()?;
         Ok(vec![(Message::new(&self.mapper_config.out_topic, payload))])
@@ -63,6 +74,7 @@ mod tests {
     use clock::Clock;
     use mqtt_channel::{Message, Topic};
     use serde_json::json;
+    use thin_edge_json::parser::Coercion;
     use time::macros::datetime;
 
     struct TestClock;
@@ -189,6 +201,31 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn converting_input_with_coercion_when_lenient() {
+        let mut converter =
+            AzureConverter::new(false, Box::new(TestClock), SizeThreshold(255 * 1024))
+                .with_coercion(Coercion::Lenient);
+
+        let input = r#"{
+            "temperature": "23.5",
+            "door_open": false
+        }"#;
+
+        let expected_output = json!({
+            "temperature": 23.5,
+            "door_open": 0.0
+        });
+
+        let output = converter.convert(&new_tedge_message(input)).await;
+
+        assert_json_eq!(
+            serde_json::from_str::<serde_json::Value>(&extract_first_message_payload(output))
+                .unwrap(),
+            expected_output
+        );
+    }
+
     #[tokio::test]
     async fn exceeding_threshold_returns_error() {
         let mut converter = AzureConverter::new(false, Box::new(TestClock), SizeThreshold(1));
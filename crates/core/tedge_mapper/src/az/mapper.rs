@@ -2,12 +2,17 @@ use std::path::Path;
 
 use crate::{
     az::converter::AzureConverter,
-    core::{component::TEdgeComponent, mapper::create_mapper, size_threshold::SizeThreshold},
+    core::{
+        component::TEdgeComponent, converter::json_coercion, mapper::create_mapper,
+        size_threshold::SizeThreshold,
+    },
 };
 
 use async_trait::async_trait;
 use clock::WallClock;
-use tedge_config::{AzureMapperTimestamp, MqttBindAddressSetting, TEdgeConfig};
+use tedge_config::{
+    AzureMapperCoercionSetting, AzureMapperTimestamp, MqttBindAddressSetting, TEdgeConfig,
+};
 use tedge_config::{ConfigSettingAccessor, MqttPortSetting};
 use tedge_utils::file::create_directory_with_user_group;
 use tracing::{info, info_span, Instrument};
@@ -51,8 +56,14 @@ impl TEdgeComponent for AzureMapper {
         let mqtt_host = tedge_config.query(MqttBindAddressSetting)?.to_string();
         let clock = Box::new(WallClock);
         let size_threshold = SizeThreshold(255 * 1024);
+        let coercion = tedge_config
+            .query_optional(AzureMapperCoercionSetting)?
+            .map(json_coercion)
+            .unwrap_or_default();
 
-        let converter = Box::new(AzureConverter::new(add_timestamp, clock, size_threshold));
+        let converter = Box::new(
+            AzureConverter::new(add_timestamp, clock, size_threshold).with_coercion(coercion),
+        );
 
         let mut mapper = create_mapper(AZURE_MAPPER_NAME, mqtt_host, mqtt_port, converter).await?;
 
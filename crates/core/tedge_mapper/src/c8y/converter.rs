@@ -32,8 +32,10 @@ use std::{
     io::Read,
     path::{Path, PathBuf},
 };
-use tedge_config::{get_tedge_config, ConfigSettingAccessor, LogPathSetting};
-use thin_edge_json::event::ThinEdgeEvent;
+use tedge_config::{
+    get_tedge_config, C8yMapperCoercionSetting, ConfigSettingAccessor, LogPathSetting,
+};
+use thin_edge_json::{event::ThinEdgeEvent, parser::Coercion};
 use time::format_description::well_known::Rfc3339;
 
 use tracing::{debug, info, log::error};
@@ -73,6 +75,7 @@ where
     operation_logs: OperationLogs,
     http_proxy: Proxy,
     cfg_dir: PathBuf,
+    coercion: Coercion,
 }
 
 impl<Proxy> CumulocityConverter<Proxy>
@@ -114,6 +117,10 @@ where
 
         let tedge_config = get_tedge_config()?;
         let logs_path = tedge_config.query(LogPathSetting)?;
+        let coercion = tedge_config
+            .query_optional(C8yMapperCoercionSetting)?
+            .map(json_coercion)
+            .unwrap_or_default();
 
         let log_dir = PathBuf::from(&format!("{}/{TEDGE_AGENT_LOG_DIR}", logs_path));
 
@@ -130,6 +137,7 @@ where
             operation_logs,
             http_proxy,
             cfg_dir: cfg_dir.to_path_buf(),
+            coercion,
         })
     }
 
@@ -185,6 +193,7 @@ where
             operation_logs,
             http_proxy,
             cfg_dir: Path::new("cfg_dir").to_path_buf(),
+            coercion: Coercion::default(),
         })
     }
 
@@ -198,8 +207,11 @@ where
         let c8y_json_payload = match maybe_child_id {
             Some(child_id) => {
                 // Need to check if the input Thin Edge JSON is valid before adding a child ID to list
-                let c8y_json_child_payload =
-                    json::from_thin_edge_json_with_child(input.payload_str()?, child_id.as_str())?;
+                let c8y_json_child_payload = json::from_thin_edge_json_with_coercion(
+                    input.payload_str()?,
+                    Some(child_id.as_str()),
+                    self.coercion,
+                )?;
 
                 if !self.children.contains(child_id.as_str()) {
                     self.children.insert(child_id.clone());
@@ -210,7 +222,9 @@ where
                 }
                 c8y_json_child_payload
             }
-            None => json::from_thin_edge_json(input.payload_str()?)?,
+            None => {
+                json::from_thin_edge_json_with_coercion(input.payload_str()?, None, self.coercion)?
+            }
         };
 
         if c8y_json_payload.len() < self.size_threshold.0 {
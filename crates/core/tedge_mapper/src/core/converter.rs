@@ -2,6 +2,8 @@ use crate::c8y::dynamic_discovery::DiscoverOp;
 use async_trait::async_trait;
 use mqtt_channel::{Message, Topic, TopicFilter};
 use std::fmt::Display;
+use tedge_config::ValueCoercion;
+use thin_edge_json::parser::Coercion;
 use tracing::error;
 
 #[derive(Debug)]
@@ -96,3 +98,11 @@ pub fn make_valid_topic_or_panic(topic_name: &str) -> Topic {
 pub fn make_valid_topic_filter_or_panic(filter_name: &str) -> TopicFilter {
     TopicFilter::new(filter_name).expect("Invalid topic filter name")
 }
+
+/// The thin-edge JSON parser coercion matching a `*.mapper.coercion` setting.
+pub fn json_coercion(value: ValueCoercion) -> Coercion {
+    match value {
+        ValueCoercion::Strict => Coercion::Strict,
+        ValueCoercion::Lenient => Coercion::Lenient,
+    }
+}
@@ -16,10 +16,19 @@ use time::{format_description, OffsetDateTime};
 pub fn parse_str<T: MeasurementVisitor>(
     input: &str,
     visitor: &mut T,
+) -> Result<(), ThinEdgeJsonParserError> {
+    parse_str_with_coercion(input, visitor, Coercion::Strict)
+}
+
+/// Parses `input` as ThinEdge JSON, applying the given `coercion` to measurement values.
+pub fn parse_str_with_coercion<T: MeasurementVisitor>(
+    input: &str,
+    visitor: &mut T,
+    coercion: Coercion,
 ) -> Result<(), ThinEdgeJsonParserError> {
     let mut deserializer = serde_json::Deserializer::from_str(input);
 
-    let parser = ThinEdgeJsonParser { visitor };
+    let parser = ThinEdgeJsonParser { visitor, coercion };
 
     deserializer
         .deserialize_map(parser)
@@ -27,6 +36,27 @@ pub fn parse_str<T: MeasurementVisitor>(
     Ok(())
 }
 
+/// How measurement values that are not plain float64 numbers are handled.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Coercion {
+    /// Only numbers in the float64, i32 or u32 range are accepted.
+    Strict,
+
+    /// Values are coerced into float64 numbers when possible:
+    ///     - `true` and `false` are read as `1.0` and `0.0`,
+    ///     - strings holding a number, as `"42"`, are read as that number,
+    ///     - integers out of the i32 or u32 range are read as the closest float64.
+    ///
+    /// Strings that do not hold a finite number, as `"NaN"` or `"inf"`, are still rejected.
+    Lenient,
+}
+
+impl Default for Coercion {
+    fn default() -> Self {
+        Coercion::Strict
+    }
+}
+
 /// The error returned by `parse_str`.
 #[derive(Debug, thiserror::Error)]
 #[error("Invalid JSON: {error}: `{input_excerpt}`")]
@@ -51,6 +81,7 @@ where
     T: MeasurementVisitor,
 {
     visitor: &'vis mut T,
+    coercion: Coercion,
 }
 
 /// Parses a single value (number) or multi-value measurement:
@@ -69,6 +100,8 @@ struct ThinEdgeValueParser<'key, 'vis, T> {
     key: Cow<'key, str>,
     /// The visitor to callback into when parsing relevant data.
     visitor: &'vis mut T,
+    /// How values that are not plain numbers are handled.
+    coercion: Coercion,
 }
 
 impl<'vis, 'de, T> de::Visitor<'de> for ThinEdgeJsonParser<'vis, T>
@@ -114,6 +147,7 @@ where
                         depth: 0,
                         key,
                         visitor: self.visitor,
+                        coercion: self.coercion,
                     };
 
                     map.next_value_seed(parser)?;
@@ -166,6 +200,7 @@ where
                 depth: self.depth + 1,
                 key,
                 visitor: self.visitor,
+                coercion: self.coercion,
             };
 
             map.next_value_seed(parser)?;
@@ -214,9 +249,12 @@ where
     where
         E: serde::de::Error,
     {
-        let value = i32::try_from(value)
-            .map_err(|_| de::Error::custom(invalid_json_number(&self.key)))?
-            .into();
+        let value = match self.coercion {
+            Coercion::Strict => i32::try_from(value)
+                .map_err(|_| de::Error::custom(invalid_json_number(&self.key)))?
+                .into(),
+            Coercion::Lenient => value as f64,
+        };
 
         self.visit_f64(value)
     }
@@ -226,12 +264,46 @@ where
     where
         E: serde::de::Error,
     {
-        let value = u32::try_from(value)
-            .map_err(|_| de::Error::custom(invalid_json_number(&self.key)))?
-            .into();
+        let value = match self.coercion {
+            Coercion::Strict => u32::try_from(value)
+                .map_err(|_| de::Error::custom(invalid_json_number(&self.key)))?
+                .into(),
+            Coercion::Lenient => value as f64,
+        };
 
         self.visit_f64(value)
     }
+
+    /// Parses a boolean as a single-value measurement, `1.0` or `0.0`, if coercion is lenient.
+    fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match self.coercion {
+            Coercion::Strict => Err(de::Error::invalid_type(de::Unexpected::Bool(value), &self)),
+            Coercion::Lenient => self.visit_f64(if value { 1.0 } else { 0.0 }),
+        }
+    }
+
+    /// Parses a string holding a number as a single-value measurement, if coercion is lenient.
+    fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+    where
+        E: serde::de::Error,
+    {
+        match self.coercion {
+            Coercion::Strict => Err(de::Error::invalid_type(de::Unexpected::Str(value), &self)),
+            Coercion::Lenient => {
+                // Rust reads "NaN" and "inf" as floats, but these are not numbers for a measurement.
+                let number = value
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .filter(|number| number.is_finite())
+                    .ok_or_else(|| de::Error::custom(invalid_string_number(&self.key, value)))?;
+                self.visit_f64(number)
+            }
+        }
+    }
 }
 
 /// The `DeserializeSeed` trait enables us to inject state required for deserialization. In our case
@@ -262,6 +334,13 @@ fn invalid_json_number(key: &str) -> String {
     )
 }
 
+fn invalid_string_number(key: &str, value: &str) -> String {
+    format!(
+        "Invalid number: the {:?} value {:?} cannot be read as a float64.",
+        key, value
+    )
+}
+
 fn invalid_timestamp(value: &str, err: impl std::fmt::Display) -> String {
     format!(
         "Invalid ISO8601 timestamp (expected YYYY-MM-DDThh:mm:ss.sss.±hh:mm): {:?}: {}",
@@ -295,6 +374,7 @@ fn map_error(error: serde_json::Error, input: &str) -> ThinEdgeJsonParserError {
 }
 #[cfg(test)]
 mod tests {
+    use test_case::test_case;
     use time::macros::datetime;
 
     use crate::parser::parse_str;
@@ -341,6 +421,112 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn it_coerces_values_when_lenient() -> anyhow::Result<()> {
+        use crate::builder::ThinEdgeJsonBuilder;
+        use crate::parser::{parse_str_with_coercion, Coercion};
+
+        let input = r#"{
+        "running": true,
+        "pressure": "123.4",
+        "counter": 4294967296,
+        "coordinate": {
+            "x": false,
+            "y": " -2 "
+        }
+    }"#;
+
+        let mut builder = ThinEdgeJsonBuilder::default();
+
+        parse_str_with_coercion(input, &mut builder, Coercion::Lenient)?;
+
+        let output = builder.done()?;
+
+        assert_eq!(
+            output.values,
+            vec![
+                ("running", 1.0).into(),
+                ("pressure", 123.4).into(),
+                ("counter", 4294967296.0).into(),
+                ("coordinate", vec![("x", 0.0).into(), ("y", -2.0).into(),]).into(),
+            ]
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_values_that_cannot_be_coerced() {
+        use crate::builder::ThinEdgeJsonBuilder;
+        use crate::parser::{parse_str_with_coercion, Coercion};
+
+        let mut builder = ThinEdgeJsonBuilder::default();
+
+        let res =
+            parse_str_with_coercion(r#"{"pressure": "high"}"#, &mut builder, Coercion::Lenient);
+
+        assert!(res
+            .unwrap_err()
+            .to_string()
+            .contains(r#"the "pressure" value "high" cannot be read as a float64"#));
+    }
+
+    #[test_case(
+        r#"{"pressure": "123.4"}"#,
+        r#"invalid type: string "123.4", expected ThinEdge single or multi-value measurement"#;
+        "a string"
+    )]
+    #[test_case(
+        r#"{"running": true}"#,
+        "invalid type: boolean `true`, expected ThinEdge single or multi-value measurement";
+        "a boolean"
+    )]
+    #[test_case(
+        r#"{"counter": 4294967296}"#,
+        r#"Number out-of-range: the "counter" value is too large to be represented as a float64."#;
+        "an integer above the u32 range"
+    )]
+    fn it_does_not_coerce_values_when_strict(input: &str, expected_error: &str) {
+        use crate::builder::ThinEdgeJsonBuilder;
+
+        let mut builder = ThinEdgeJsonBuilder::default();
+
+        let res = parse_str(input, &mut builder);
+
+        let error = res.unwrap_err().to_string();
+        assert!(
+            error.contains(expected_error),
+            "{:?} does not contain {:?}",
+            error,
+            expected_error
+        );
+    }
+
+    #[test_case("NaN")]
+    #[test_case("inf")]
+    #[test_case("-infinity")]
+    #[test_case("1e400")]
+    fn it_does_not_coerce_strings_that_are_not_finite_numbers(value: &str) {
+        use crate::builder::ThinEdgeJsonBuilder;
+        use crate::parser::{parse_str_with_coercion, Coercion};
+
+        let input = format!(r#"{{"pressure": {:?}}}"#, value);
+        let mut builder = ThinEdgeJsonBuilder::default();
+
+        let res = parse_str_with_coercion(&input, &mut builder, Coercion::Lenient);
+
+        let error = res.unwrap_err().to_string();
+        let expected_error = format!(
+            r#"the "pressure" value {:?} cannot be read as a float64"#,
+            value
+        );
+        assert!(
+            error.contains(&expected_error),
+            "{:?} does not contain {:?}",
+            error,
+            expected_error
+        );
+    }
+
     #[test]
     fn it_shows_input_excerpt_on_error() -> anyhow::Result<()> {
         use crate::builder::ThinEdgeJsonBuilder;